@@ -2,6 +2,8 @@ use anyhow::{anyhow, Result};
 use chrono::prelude::*;
 use colored::*;
 use std::env;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::RwLock;
 
 /// Defines the four main logging output levels
 #[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
@@ -10,6 +12,7 @@ pub enum LogEntryLevel {
     WARN = 0x1,
     INFO = 0x2,
     DEBUG = 0x3,
+    TRACE = 0x4,
 }
 
 const DEFAULT_DATETIME_PRINT_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
@@ -17,6 +20,7 @@ const DEFAULT_DATETIME_PRINT_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
 impl LogEntryLevel {
     pub fn from_string(s: &str) -> Result<LogEntryLevel> {
         match s.to_uppercase().as_str() {
+            "TRACE" => Ok(LogEntryLevel::TRACE),
             "DEBUG" => Ok(LogEntryLevel::DEBUG),
             "INFO" => Ok(LogEntryLevel::INFO),
             "WARN" => Ok(LogEntryLevel::WARN),
@@ -39,23 +43,141 @@ impl LogEntryLevel {
         if let Ok(e) = env::var(env_var_name) {
             LogEntryLevel::from_string(&e)
         } else {
-            Ok(unsafe { MIN_LOG_LEVEL })
+            Ok(get_min_log_level())
         }
     }
+
+    /// Encodes the level as the `u8` stored in the atomic min-level global.
+    fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Decodes a level previously stored with [`LogEntryLevel::as_u8`], treating
+    /// unknown values as `INFO`.
+    fn from_u8(value: u8) -> LogEntryLevel {
+        match value {
+            0x0 => LogEntryLevel::ERROR,
+            0x1 => LogEntryLevel::WARN,
+            0x2 => LogEntryLevel::INFO,
+            0x3 => LogEntryLevel::DEBUG,
+            0x4 => LogEntryLevel::TRACE,
+            _ => LogEntryLevel::INFO,
+        }
+    }
+}
+
+/// A single parsed entry from a log-level filter spec such as
+/// `warn,stump::io=debug,net=trace`. The `String` is a module/path prefix and
+/// the level is what that prefix enables. A directive with no prefix (`None`)
+/// sets the default level applied when nothing more specific matches.
+type LogFilter = (Option<String>, LogEntryLevel);
+
+/// Parsed form of the `STUMP_LOG_AT_LEVEL` directive list. Populated once, either
+/// lazily from the environment or explicitly via `set_filters`. `None` means no
+/// spec has been configured, in which case the plain `MIN_LOG_LEVEL` applies.
+static LOG_FILTERS: RwLock<Option<Vec<LogFilter>>> = RwLock::new(None);
+
+/// Reads the raw (unparsed) log-level spec from the environment, honoring the
+/// optional `LOG_LEVEL_VAR_NAME` build-time override of the variable name.
+fn log_level_env_spec() -> Option<String> {
+    let env_var_name = if let Some(v) = option_env!("LOG_LEVEL_VAR_NAME") {
+        v.to_string()
+    } else {
+        "STUMP_LOG_AT_LEVEL".to_string()
+    };
+    env::var(env_var_name).ok()
+}
+
+/// Parses a comma-separated directive list into filter entries. An entry of the
+/// form `prefix=level` scopes a level to a module/path prefix; a bare `level`
+/// sets the default. Whitespace is tolerated, the level is case-insensitive, and
+/// malformed entries are skipped rather than failing the whole spec.
+fn parse_filters(spec: &str) -> Vec<LogFilter> {
+    spec.split(',')
+        .filter_map(|directive| {
+            let directive = directive.trim();
+            if directive.is_empty() {
+                return None;
+            }
+            if let Some((prefix, level)) = directive.split_once('=') {
+                let prefix = prefix.trim();
+                if prefix.is_empty() {
+                    return None;
+                }
+                let level = LogEntryLevel::from_string(level.trim()).ok()?;
+                Some((Some(prefix.to_string()), level))
+            } else {
+                let level = LogEntryLevel::from_string(directive).ok()?;
+                Some((None, level))
+            }
+        })
+        .collect()
+}
+
+/// Resolves the effective level for `module` from a parsed directive list: the
+/// level of the longest prefix that matches, else the default (prefix-less)
+/// directive, else `INFO`.
+fn resolve_filter_level(filters: &[LogFilter], module: &str) -> LogEntryLevel {
+    let mut best: Option<(usize, LogEntryLevel)> = None;
+    let mut default: Option<LogEntryLevel> = None;
+    for (prefix, level) in filters {
+        match prefix {
+            Some(prefix)
+                if module.starts_with(prefix.as_str())
+                    && best.is_none_or(|(best_len, _)| prefix.len() > best_len) =>
+            {
+                best = Some((prefix.len(), *level));
+            }
+            None => default = Some(*level),
+            _ => {}
+        }
+    }
+    best.map(|(_, level)| level)
+        .or(default)
+        .unwrap_or(LogEntryLevel::INFO)
+}
+
+/// Installs a log-level filter spec programmatically, mirroring the parsing of
+/// the `STUMP_LOG_AT_LEVEL` environment variable. Accepts a comma-separated
+/// directive list like `warn,stump::io=debug,net=trace`.
+pub fn set_filters(spec: &str) {
+    *LOG_FILTERS.write().unwrap() = Some(parse_filters(spec));
+}
+
+/// Returns the effective minimum log level for `module`, consulting the parsed
+/// filter spec (from `set_filters` or the environment). When no spec is
+/// configured, the global `MIN_LOG_LEVEL` applies.
+pub fn level_for_module(module: &str) -> LogEntryLevel {
+    {
+        let guard = LOG_FILTERS.read().unwrap();
+        if let Some(filters) = guard.as_ref() {
+            return resolve_filter_level(filters, module);
+        }
+    }
+    // No spec cached yet: parse the environment once and cache it, otherwise
+    // defer to the plain global minimum.
+    if let Some(spec) = log_level_env_spec() {
+        let parsed = parse_filters(&spec);
+        let level = resolve_filter_level(&parsed, module);
+        *LOG_FILTERS.write().unwrap() = Some(parsed);
+        level
+    } else {
+        get_min_log_level()
+    }
 }
 
 /// Global indicator for verbose output when printing via `vprintln` and/or `veprintln`. Not meant to be set directly,
 /// instead via `set_verbose`
-static mut IS_VERBOSE: bool = false;
+static IS_VERBOSE: AtomicBool = AtomicBool::new(false);
 
 /// Program-controlled minimum log level. Controls the printing of messages via `debug!()`, `info!()`, `warn!()`, and `error!()`
-static mut MIN_LOG_LEVEL: LogEntryLevel = LogEntryLevel::WARN;
+static MIN_LOG_LEVEL: AtomicU8 = AtomicU8::new(LogEntryLevel::WARN as u8);
 
 /// Type definition for optional callbacks to capture log output.
 type FnPrint = dyn Fn(&String) + Send + Sync + 'static;
 
 /// Global storage point for optional output callback.
-static mut PRINT: Option<Box<FnPrint>> = None;
+static PRINT: RwLock<Option<Box<FnPrint>>> = RwLock::new(None);
 
 /// Provides an alternative print method for integration with other command line
 /// options such as `informif`.
@@ -76,22 +198,48 @@ static mut PRINT: Option<Box<FnPrint>> = None;
 /// }
 /// ```
 pub fn set_print<F: Fn(&String) + Send + Sync + 'static>(f: F) {
-    unsafe {
-        PRINT = Some(Box::new(f));
-    }
+    *PRINT.write().unwrap() = Some(Box::new(f));
 }
 
 /// Print the string to stdout, or if present, a user-provided print closure.
 pub fn do_println(s: &String) {
-    unsafe {
-        if let Some(p) = &PRINT {
-            p(s);
-        } else {
-            println!("{}", s);
-        }
+    let guard = PRINT.read().unwrap();
+    if let Some(p) = guard.as_ref() {
+        p(s);
+    } else {
+        println!("{}", s);
     }
 }
 
+/// Print the string to stderr, or if present, the same user-provided print
+/// closure used by [`do_println`]. Used to route higher-severity records to
+/// stderr so they survive stdout redirection.
+pub fn do_eprintln(s: &String) {
+    let guard = PRINT.read().unwrap();
+    if let Some(p) = guard.as_ref() {
+        p(s);
+    } else {
+        eprintln!("{}", s);
+    }
+}
+
+/// Severity cutoff, inclusive, at or above which records are routed to stderr
+/// instead of stdout. Defaults to `WARN`, so ERROR and WARN go to stderr.
+static STDERR_THRESHOLD: AtomicU8 = AtomicU8::new(LogEntryLevel::WARN as u8);
+
+/// Sets the severity cutoff at or above which records are written to stderr.
+/// Records more severe than or equal to `level` (ERROR being the most severe)
+/// go to stderr; everything else stays on stdout.
+pub fn set_stderr_threshold(level: LogEntryLevel) {
+    STDERR_THRESHOLD.store(level.as_u8(), Ordering::Relaxed);
+}
+
+/// Indicates whether a record at `level` should be routed to stderr under the
+/// current threshold.
+pub fn is_stderr_level(level: LogEntryLevel) -> bool {
+    level.as_u8() <= STDERR_THRESHOLD.load(Ordering::Relaxed)
+}
+
 /// Sets whether the verbose standard print macro prints to stdout or stays silent
 ///
 /// # Example
@@ -112,31 +260,114 @@ pub fn do_println(s: &String) {
 /// vprintln("Again nothing will print");
 /// ```
 pub fn set_verbose(v: bool) {
-    unsafe {
-        IS_VERBOSE = v;
-    }
+    IS_VERBOSE.store(v, Ordering::Relaxed);
 }
 
 /// Indicates whether the verbose flag is set
 pub fn is_verbose() -> bool {
-    unsafe { IS_VERBOSE }
+    IS_VERBOSE.load(Ordering::Relaxed)
 }
 
 /// Sets the global minimum logging level. Can be user-overridden with `STUMPLOG_AT_LEVEL`
 pub fn set_min_log_level(min_log_level: LogEntryLevel) {
-    unsafe {
-        MIN_LOG_LEVEL = min_log_level;
-    }
+    MIN_LOG_LEVEL.store(min_log_level.as_u8(), Ordering::Relaxed);
 }
 
 /// Retrieves the global minimum logging level. Does not check the user env var.
 pub fn get_min_log_level() -> LogEntryLevel {
-    unsafe { MIN_LOG_LEVEL }
+    LogEntryLevel::from_u8(MIN_LOG_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Fluent one-shot configuration of stump's global logging state.
+///
+/// Rather than reach for the individual `set_*` functions, embedders can
+/// configure the minimum level, verbose flag, datetime format, output
+/// formatter, and print callback in a single chain and install them all at once
+/// with [`LoggerBuilder::init`].
+///
+/// # Example
+/// ```no_run
+/// stump::LoggerBuilder::new()
+///     .min_log_level(stump::LogEntryLevel::INFO)
+///     .verbose(true)
+///     .formatter(stump::JsonFormatter)
+///     .init();
+/// ```
+#[derive(Default)]
+pub struct LoggerBuilder {
+    min_log_level: Option<LogEntryLevel>,
+    verbose: Option<bool>,
+    datetime_format: Option<String>,
+    formatter: Option<Box<dyn Formatter>>,
+    print: Option<Box<FnPrint>>,
+}
+
+impl LoggerBuilder {
+    /// Creates an empty builder that leaves every unset field untouched on
+    /// [`LoggerBuilder::init`].
+    pub fn new() -> LoggerBuilder {
+        LoggerBuilder::default()
+    }
+
+    /// Sets the global minimum logging level.
+    pub fn min_log_level(mut self, level: LogEntryLevel) -> LoggerBuilder {
+        self.min_log_level = Some(level);
+        self
+    }
+
+    /// Sets the verbose flag consulted by `vprintln!`/`veprintln!`.
+    pub fn verbose(mut self, verbose: bool) -> LoggerBuilder {
+        self.verbose = Some(verbose);
+        self
+    }
+
+    /// Overrides the datetime format used when rendering timestamps.
+    pub fn datetime_format(mut self, format: &str) -> LoggerBuilder {
+        self.datetime_format = Some(format.to_string());
+        self
+    }
+
+    /// Installs the output formatter consulted by the logging macros.
+    pub fn formatter<F: Formatter + 'static>(mut self, formatter: F) -> LoggerBuilder {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Installs a print callback capturing log output, as with [`set_print`].
+    pub fn print<F: Fn(&String) + Send + Sync + 'static>(mut self, f: F) -> LoggerBuilder {
+        self.print = Some(Box::new(f));
+        self
+    }
+
+    /// Applies every configured field to the global state in one call.
+    pub fn init(self) {
+        if let Some(level) = self.min_log_level {
+            MIN_LOG_LEVEL.store(level.as_u8(), Ordering::Relaxed);
+        }
+        if let Some(verbose) = self.verbose {
+            IS_VERBOSE.store(verbose, Ordering::Relaxed);
+        }
+        if let Some(format) = self.datetime_format {
+            *DATETIME_FORMAT.write().unwrap() = Some(format);
+        }
+        if let Some(formatter) = self.formatter {
+            *FORMATTER.write().unwrap() = Some(formatter);
+        }
+        if let Some(print) = self.print {
+            *PRINT.write().unwrap() = Some(print);
+        }
+    }
 }
 
 /// Returns a data time format string that should be used for logging. Will be either the default
 /// string, or a custom one if it exists in the environment variable `STUMP_LOG_DATETIME_FORMAT`.
 fn get_log_datetime_format_string() -> String {
+    // A program-configured format (via `set_datetime_format` or `LoggerBuilder`)
+    // takes precedence over the environment and the built-in default.
+    if let Some(fmt) = DATETIME_FORMAT.read().unwrap().as_ref() {
+        return fmt.clone();
+    }
+
     // If you don't like the default "STUMP_LOG_AT_LEVEL", set what you'd like in a build var
     // LOG_LEVEL_VAR_NAME
     let env_var_name = if let Some(v) = option_env!("LOG_DATETIME_FORMAT_VAR_NAME") {
@@ -152,6 +383,16 @@ fn get_log_datetime_format_string() -> String {
     }
 }
 
+/// Program-configured datetime format override. When `None`, the environment
+/// variable or the built-in default is used.
+static DATETIME_FORMAT: RwLock<Option<String>> = RwLock::new(None);
+
+/// Sets the datetime format used when rendering log timestamps, overriding both
+/// the environment variable and the built-in default.
+pub fn set_datetime_format(format: &str) {
+    *DATETIME_FORMAT.write().unwrap() = Some(format.to_string());
+}
+
 /// Formats the current date and time (now) using either the default data format or one specified in the
 /// environment variable `STUMP_LOG_DATETIME_FORMAT`
 pub fn format_datetime() -> String {
@@ -161,6 +402,193 @@ pub fn format_datetime() -> String {
     )
 }
 
+/// Renders a single status record into its final printable form.
+///
+/// Implementors control the entire line layout, letting programs swap the
+/// default human-readable console output for a machine-parseable format without
+/// touching the logging macros. `ts` is the preformatted timestamp (with its
+/// trailing separator) as produced by [`format_datetime`].
+pub trait Formatter: Send + Sync {
+    fn format(&self, level: LogEntryLevel, module: &str, line: u32, msg: &str, ts: &str)
+        -> String;
+}
+
+/// The default, pretty console format matching stump's historical output.
+pub struct HumanFormatter;
+
+/// A syslog-friendly format: a numeric priority prefix and no ANSI color, one
+/// record per line.
+pub struct SyslogFormatter;
+
+/// A JSON-lines format emitting one object per record, suitable for piping into
+/// log aggregators.
+pub struct JsonFormatter;
+
+impl Formatter for HumanFormatter {
+    fn format(
+        &self,
+        level: LogEntryLevel,
+        module: &str,
+        line: u32,
+        msg: &str,
+        ts: &str,
+    ) -> String {
+        format!("{} {:?} {}:{} {}", ts, level, module, line, msg)
+    }
+}
+
+impl Formatter for SyslogFormatter {
+    fn format(
+        &self,
+        level: LogEntryLevel,
+        module: &str,
+        line: u32,
+        msg: &str,
+        ts: &str,
+    ) -> String {
+        let priority = match level {
+            LogEntryLevel::ERROR => 3,
+            LogEntryLevel::WARN => 4,
+            LogEntryLevel::INFO => 6,
+            LogEntryLevel::DEBUG => 7,
+            LogEntryLevel::TRACE => 7,
+        };
+        format!("<{}>{} {}:{} {}", priority, ts, module, line, msg)
+    }
+}
+
+impl Formatter for JsonFormatter {
+    fn format(
+        &self,
+        level: LogEntryLevel,
+        module: &str,
+        line: u32,
+        msg: &str,
+        ts: &str,
+    ) -> String {
+        format!(
+            "{{\"ts\":\"{}\",\"level\":\"{:?}\",\"module\":\"{}\",\"line\":{},\"msg\":\"{}\"}}",
+            json_escape(ts.trim()),
+            level,
+            json_escape(module),
+            line,
+            json_escape(msg)
+        )
+    }
+}
+
+/// Escapes the characters that would otherwise break a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Active output formatter. When unset, [`HumanFormatter`] is used.
+static FORMATTER: RwLock<Option<Box<dyn Formatter>>> = RwLock::new(None);
+
+/// Installs the active output formatter consulted by the logging macros.
+pub fn set_formatter<F: Formatter + 'static>(f: F) {
+    *FORMATTER.write().unwrap() = Some(Box::new(f));
+}
+
+/// Formats a status record through the active formatter, supplying the current
+/// timestamp. Used by the `status!` macro in place of an inline `format!`.
+pub fn format_status(level: LogEntryLevel, module: &str, line: u32, msg: &str) -> String {
+    let ts = format_datetime();
+    let guard = FORMATTER.read().unwrap();
+    match guard.as_ref() {
+        Some(f) => f.format(level, module, line, msg, &ts),
+        None => HumanFormatter.format(level, module, line, msg, &ts),
+    }
+}
+
+/// Formats a status record and writes it to stdout or stderr according to the
+/// active stderr threshold. Used by the logging macros after level and message
+/// filtering have passed.
+pub fn emit(level: LogEntryLevel, module: &str, line: u32, msg: &str) {
+    let out = format_status(level, module, line, msg);
+    if is_stderr_level(level) {
+        do_eprintln(&out);
+    } else {
+        do_println(&out);
+    }
+}
+
+/// The compiled message filter. A [`regex::Regex`] when the `regex` feature is
+/// enabled, otherwise a plain substring matched against the message.
+#[cfg(feature = "regex")]
+pub type MessageFilter = regex::Regex;
+
+/// The compiled message filter. A [`regex::Regex`] when the `regex` feature is
+/// enabled, otherwise a plain substring matched against the message.
+#[cfg(not(feature = "regex"))]
+pub type MessageFilter = String;
+
+/// The active message filter. `None` disables the gate. Populated lazily from
+/// the `STUMP_LOG_FILTER` environment variable or explicitly via
+/// [`set_message_filter`].
+static MESSAGE_FILTER: RwLock<Option<MessageFilter>> = RwLock::new(None);
+
+/// Whether the message filter has been resolved (from the environment or a
+/// [`set_message_filter`] call) yet. Distinguishes an unset filter from one that
+/// was deliberately cleared.
+static MESSAGE_FILTER_LOADED: AtomicBool = AtomicBool::new(false);
+
+/// Compiles the raw filter string into a [`MessageFilter`], or `None` when the
+/// string is empty (which disables the gate). Under the `regex` feature an
+/// uncompilable pattern is also treated as no filter.
+fn compile_message_filter(spec: &str) -> Option<MessageFilter> {
+    if spec.is_empty() {
+        return None;
+    }
+    #[cfg(feature = "regex")]
+    {
+        regex::Regex::new(spec).ok()
+    }
+    #[cfg(not(feature = "regex"))]
+    {
+        Some(spec.to_string())
+    }
+}
+
+/// Installs the active message filter, overriding the `STUMP_LOG_FILTER`
+/// environment variable. Passing `None` disables the gate.
+pub fn set_message_filter(filter: Option<MessageFilter>) {
+    *MESSAGE_FILTER.write().unwrap() = filter;
+    MESSAGE_FILTER_LOADED.store(true, Ordering::Relaxed);
+}
+
+/// Returns whether `msg` passes the active message filter. With no filter
+/// configured the gate is disabled and every message passes.
+pub fn message_matches(msg: &str) -> bool {
+    if !MESSAGE_FILTER_LOADED.load(Ordering::Relaxed) {
+        let compiled = env::var("STUMP_LOG_FILTER")
+            .ok()
+            .and_then(|spec| compile_message_filter(&spec));
+        *MESSAGE_FILTER.write().unwrap() = compiled;
+        MESSAGE_FILTER_LOADED.store(true, Ordering::Relaxed);
+    }
+
+    let guard = MESSAGE_FILTER.read().unwrap();
+    match guard.as_ref() {
+        #[cfg(feature = "regex")]
+        Some(filter) => filter.is_match(msg),
+        #[cfg(not(feature = "regex"))]
+        Some(filter) => msg.contains(filter.as_str()),
+        None => true,
+    }
+}
+
 /// Print to stdout if user specified increased output verbosity
 #[macro_export]
 macro_rules! vprintln {
@@ -187,7 +615,7 @@ macro_rules! veprintln {
 #[macro_export]
 macro_rules! status {
     ($level:expr, $($arg:tt)*) => {
-        println!("{} {:?} {}:{} {}", $crate::format_datetime(), $level, file!(), line!(), format!($($arg)*));
+        $crate::emit($level, file!(), line!(), &format!($($arg)*));
     };
 }
 
@@ -195,15 +623,24 @@ macro_rules! status {
 #[macro_export]
 macro_rules! status_at_or_above {
     ($level:expr, $($arg:tt)*) => {
-        if let Ok(min_log_level) = $crate::LogEntryLevel::from_env() {
-            if min_log_level >= $level {
-                status!($level, $($arg)*);
+        if $crate::level_for_module(module_path!()) >= $level {
+            let msg = format!($($arg)*);
+            if $crate::message_matches(&msg) {
+                $crate::emit($level, file!(), line!(), &msg);
             }
         }
     };
 }
 
-/// Prints messages at the DEBUG (lowest) level. Accepts standard `println` formatting.
+/// Prints messages at the TRACE (lowest) level. Accepts standard `println` formatting.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        status_at_or_above!($crate::LogEntryLevel::TRACE, $($arg)*);
+    };
+}
+
+/// Prints messages at the DEBUG level. Accepts standard `println` formatting.
 #[macro_export]
 macro_rules! debug {
     ($($arg:tt)*) => {
@@ -340,6 +777,85 @@ fn format_complete(file_base_name: &str, status: CompleteStatus) -> String {
     )
 }
 
+/// Bridges the standard [`log`] crate facade onto stump's output pipeline.
+///
+/// Enabling the `log-facade` feature lets any dependency that emits through the
+/// `log` crate's `error!`/`warn!`/`info!`/`debug!`/`trace!` macros share stump's
+/// formatting, color, and print-callback behavior via [`init_log_facade`].
+///
+/// The `log-facade` feature must pull in `log` with its `std` feature, as
+/// [`init_log_facade`] calls [`log::set_boxed_logger`] which is gated behind
+/// `log/std`. The manifest therefore declares the feature as
+/// `log-facade = ["dep:log", "log/std"]`.
+#[cfg(feature = "log-facade")]
+mod facade {
+    use super::*;
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    /// A [`log::Log`] backend that routes records through stump's formatting and
+    /// print-callback pipeline.
+    pub struct StumpLogger;
+
+    /// Maps a `log` level onto stump's own level set. `log`'s `Trace` collapses
+    /// to stump's `DEBUG`; stump's own `TRACE` level is not produced here.
+    fn map_level(level: Level) -> LogEntryLevel {
+        match level {
+            Level::Error => LogEntryLevel::ERROR,
+            Level::Warn => LogEntryLevel::WARN,
+            Level::Info => LogEntryLevel::INFO,
+            Level::Debug | Level::Trace => LogEntryLevel::DEBUG,
+        }
+    }
+
+    /// Expresses the global minimum level as a `log` [`LevelFilter`] so the
+    /// facade short-circuits records stump would drop anyway.
+    fn min_level_filter() -> LevelFilter {
+        match get_min_log_level() {
+            LogEntryLevel::ERROR => LevelFilter::Error,
+            LogEntryLevel::WARN => LevelFilter::Warn,
+            LogEntryLevel::INFO => LevelFilter::Info,
+            LogEntryLevel::DEBUG => LevelFilter::Debug,
+            LogEntryLevel::TRACE => LevelFilter::Trace,
+        }
+    }
+
+    impl Log for StumpLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            get_min_log_level() >= map_level(metadata.level())
+        }
+
+        fn log(&self, record: &Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+            let msg = record.args().to_string();
+            if message_matches(&msg) {
+                emit(
+                    map_level(record.level()),
+                    record.target(),
+                    record.line().unwrap_or(0),
+                    &msg,
+                );
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Installs [`StumpLogger`] as the global `log` backend and sets the max
+    /// level from the current global minimum, wiring every `log`-using
+    /// dependency into stump's formatting and print-callback pipeline in one
+    /// call.
+    pub fn init_log_facade() -> std::result::Result<(), log::SetLoggerError> {
+        log::set_boxed_logger(Box::new(StumpLogger))?;
+        log::set_max_level(min_level_filter());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "log-facade")]
+pub use facade::{init_log_facade, StumpLogger};
+
 /// Prints a simple message indicating experimental status of a function.
 ///
 /// # Example
@@ -350,3 +866,66 @@ fn format_complete(file_base_name: &str, status: CompleteStatus) -> String {
 pub fn print_experimental() {
     do_println(&format!("{} - Results may vary, bugs will be present, and not all functionality has been implemented", "Experimental Code!".red()));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_filters_tolerates_whitespace_and_case() {
+        let filters = parse_filters("  WARN , stump::io = Debug , net=TRACE ");
+        assert_eq!(
+            filters,
+            vec![
+                (None, LogEntryLevel::WARN),
+                (Some("stump::io".to_string()), LogEntryLevel::DEBUG),
+                (Some("net".to_string()), LogEntryLevel::TRACE),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_filters_skips_malformed_entries() {
+        let filters = parse_filters("info,stump::io=nonsense,,=debug,net=trace");
+        assert_eq!(
+            filters,
+            vec![
+                (None, LogEntryLevel::INFO),
+                (Some("net".to_string()), LogEntryLevel::TRACE),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_filter_level_prefers_longest_prefix() {
+        let filters = parse_filters("warn,stump=info,stump::io=debug");
+        assert_eq!(
+            resolve_filter_level(&filters, "stump::io::reader"),
+            LogEntryLevel::DEBUG
+        );
+        assert_eq!(
+            resolve_filter_level(&filters, "stump::net"),
+            LogEntryLevel::INFO
+        );
+    }
+
+    #[test]
+    fn resolve_filter_level_falls_back_to_default_then_info() {
+        let with_default = parse_filters("error,stump::io=debug");
+        assert_eq!(
+            resolve_filter_level(&with_default, "unrelated"),
+            LogEntryLevel::ERROR
+        );
+
+        let without_default = parse_filters("stump::io=debug");
+        assert_eq!(
+            resolve_filter_level(&without_default, "unrelated"),
+            LogEntryLevel::INFO
+        );
+    }
+
+    #[test]
+    fn json_escape_escapes_control_and_quote_characters() {
+        assert_eq!(json_escape("a\"b\\c\n\r\t"), "a\\\"b\\\\c\\n\\r\\t");
+    }
+}